@@ -0,0 +1,218 @@
+use crate::gadgets::poseidon_transcript::PoseidonTranscriptVar;
+use ark_crypto_primitives::sponge::poseidon::PoseidonConfig;
+use ark_ec::CurveGroup;
+use ark_ff::PrimeField;
+use ark_r1cs_std::{
+    boolean::Boolean, eq::EqGadget, fields::ToConstraintFieldGadget, fields::fp::FpVar,
+    groups::CurveVar,
+};
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
+
+// PolyCircuit(circuits/poly.rs)은 다항식의 계수를 전부 상수(public parameter)로 박아넣기
+// 때문에, committed polynomial에 대한 평가를 증명할 방법이 없다. 이 모듈은 KZG류 commitment에
+// 대한 evaluation proof를 in-circuit에서 검증하는 gadget을 제공한다
+//
+// 표준 KZG 검증식: e(C - [y]G, H) == e(W, τH - zH)
+// 이 crate의 나머지 gadget들이 그렇듯, pairing이 없는 curve(ed_on_bn254 등)로도 테스트할 수 있어야
+// 하므로, pairing 수행 자체는 `PairingCheckVar` trait으로 추상화해 분리한다
+
+// e(lhs_g1, lhs_g2) == e(rhs_g1, rhs_g2) 를 검증하는 인터페이스.
+// 실제 pairing-friendly curve에서는 miller loop + final exponentiation으로 구현하고, 이때
+// tau_minus_z_bits는 사용하지 않는다. pairing이 없는 테스트 curve에서는 LinearCombinationCheck로
+// 대체하는데, pairing 없이는 4개의 group element만으로 관계를 검증할 수 없으므로 τ-z를 공개
+// scalar bit 벡터로 함께 받아 scalar_mul_le 기반 선형결합 항등식으로 대신 체크한다
+pub trait PairingCheckVar<C: CurveGroup, GG: CurveVar<C, C::BaseField>> {
+    fn check_equal_pairings(
+        lhs_g1: &GG,
+        lhs_g2: &GG,
+        rhs_g1: &GG,
+        rhs_g2: &GG,
+        tau_minus_z_bits: &[Boolean<C::BaseField>],
+    ) -> Result<(), SynthesisError>;
+}
+
+// pairing이 없는 curve에서 사용하는 축소된 검증: τ-z를 공개 scalar(bit 벡터)로 직접 받아
+// C - yG == τH - zH가 아니라 W에 대한 선형결합 항등식으로 체크한다.
+// (실제 KZG에서는 τ가 비공개여야 하지만, pairing이 없는 테스트 curve에서는 이 방식으로만
+// scalar_mul_le 기반 관계를 재현할 수 있으므로 test-only 용도로 제공한다)
+pub struct LinearCombinationCheck;
+
+impl LinearCombinationCheck {
+    pub fn check<C, GG>(
+        diff: &GG,
+        w: &GG,
+        tau_minus_z_bits: &[Boolean<C::BaseField>],
+    ) -> Result<(), SynthesisError>
+    where
+        C: CurveGroup,
+        GG: CurveVar<C, C::BaseField>,
+    {
+        let rhs = w.scalar_mul_le(tau_minus_z_bits.iter())?;
+        diff.enforce_equal(&rhs)
+    }
+}
+
+impl<C, GG> PairingCheckVar<C, GG> for LinearCombinationCheck
+where
+    C: CurveGroup,
+    GG: CurveVar<C, C::BaseField>,
+{
+    // pairing 대신 diff == (τ-z)·W 를 직접 체크한다. lhs_g2(H), rhs_g2(τH-zH)는 실제 pairing
+    // 구현체를 위한 group element이므로 이 fallback에서는 쓰이지 않는다
+    fn check_equal_pairings(
+        lhs_g1: &GG,
+        _lhs_g2: &GG,
+        rhs_g1: &GG,
+        _rhs_g2: &GG,
+        tau_minus_z_bits: &[Boolean<C::BaseField>],
+    ) -> Result<(), SynthesisError> {
+        Self::check::<C, GG>(lhs_g1, rhs_g1, tau_minus_z_bits)
+    }
+}
+
+pub struct KzgVerifierGadget;
+
+impl KzgVerifierGadget {
+    // commitment C, 평가점 z, 평가값 y, quotient commitment W가 주어졌을 때
+    // C - [y]G 와 W를 구성하고, 공급된 pairing-check 구현체로 e(C-yG, H) == e(W, τH-zH)를 강제한다
+    pub fn verify<C, GG, P>(
+        commitment: &GG,
+        y: &FpVar<C::BaseField>,
+        g: &GG,
+        w: &GG,
+        h: &GG,
+        tau_h_minus_zh: &GG,
+        tau_minus_z_bits: &[Boolean<C::BaseField>],
+    ) -> Result<(), SynthesisError>
+    where
+        C: CurveGroup,
+        C::BaseField: PrimeField,
+        GG: CurveVar<C, C::BaseField>,
+        P: PairingCheckVar<C, GG>,
+    {
+        let y_bits = to_scalar_bits::<C>(y)?;
+        let y_g = g.scalar_mul_le(y_bits.iter())?;
+        let diff = commitment.clone() - y_g;
+
+        P::check_equal_pairings(&diff, h, w, tau_h_minus_zh, tau_minus_z_bits)
+    }
+}
+
+// FpVar(base field)의 bit 표현을 scalar_mul_le에 넘길 수 있는 bit 시퀀스로 변환
+fn to_scalar_bits<C: CurveGroup>(
+    y: &FpVar<C::BaseField>,
+) -> Result<Vec<ark_r1cs_std::boolean::Boolean<C::BaseField>>, SynthesisError>
+where
+    C::BaseField: PrimeField,
+{
+    use ark_r1cs_std::prelude::ToBitsGadget;
+    y.to_bits_le()
+}
+
+pub struct HyperKzgVerifierGadget;
+
+impl HyperKzgVerifierGadget {
+    // multilinear/HyperKZG variant: round별 commitment들을 Poseidon transcript에서 squeeze한
+    // challenge로 접어(fold) 최종 commitment를 얻는다. 각 challenge는 이전 round들의 absorb에
+    // 의존하므로 순서대로 처리해야 한다
+    pub fn fold_commitments<C, GG>(
+        cs: ConstraintSystemRef<C::BaseField>,
+        config: &PoseidonConfig<C::BaseField>,
+        round_commitments: &[GG],
+    ) -> Result<GG, SynthesisError>
+    where
+        C: CurveGroup,
+        C::BaseField: PrimeField,
+        GG: CurveVar<C, C::BaseField> + ToConstraintFieldGadget<C::BaseField>,
+    {
+        use ark_r1cs_std::alloc::AllocVar;
+
+        if round_commitments.is_empty() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let zero = FpVar::new_constant(cs.clone(), C::BaseField::from(0u64))?;
+        let mut transcript = PoseidonTranscriptVar::new(cs, config, zero)?;
+
+        let mut acc = round_commitments[0].clone();
+        transcript.append_vector(&acc.to_constraint_field()?)?;
+        for commitment in round_commitments[1..].iter() {
+            transcript.append_vector(&commitment.to_constraint_field()?)?;
+            let challenge = transcript.challenge()?;
+            let challenge_bits = {
+                use ark_r1cs_std::prelude::ToBitsGadget;
+                challenge.to_bits_le()?
+            };
+            acc += commitment.scalar_mul_le(challenge_bits.iter())?;
+        }
+        Ok(acc)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadgets::poseidon_transcript::test_config;
+    use ark_ed_on_bn254::{Fq, Fr, EdwardsProjective, constraints::EdwardsVar};
+    use ark_ff::BigInteger;
+    use ark_r1cs_std::alloc::AllocVar;
+    use ark_relations::r1cs::ConstraintSystem;
+    use ark_std::{UniformRand, test_rng};
+
+    #[test]
+    fn test_kzg_verify_with_linear_combination_check() {
+        let rng = &mut test_rng();
+        let cs = ConstraintSystem::<Fq>::new_ref();
+
+        let g = EdwardsProjective::rand(rng);
+        let h = EdwardsProjective::rand(rng);
+        let w = EdwardsProjective::rand(rng);
+        let y = Fr::from(7u64);
+        let tau_minus_z = Fr::from(11u64);
+
+        // commitment을 C = yG + (τ-z)W 로 구성하면 diff = C - yG = (τ-z)W 가 되어
+        // LinearCombinationCheck가 기대하는 선형결합 관계를 만족한다
+        let commitment = g * y + w * tau_minus_z;
+        let tau_h_minus_zh = h * tau_minus_z;
+
+        let g_var = EdwardsVar::new_constant(cs.clone(), g).unwrap();
+        let h_var = EdwardsVar::new_constant(cs.clone(), h).unwrap();
+        let w_var = EdwardsVar::new_witness(cs.clone(), || Ok(w)).unwrap();
+        let commitment_var = EdwardsVar::new_witness(cs.clone(), || Ok(commitment)).unwrap();
+        let tau_h_minus_zh_var = EdwardsVar::new_witness(cs.clone(), || Ok(tau_h_minus_zh)).unwrap();
+        // y는 base field(Fq) 위의 FpVar로 표현되므로, 같은 작은 정수값을 Fq 원소로 다시 witness한다
+        let y_var = FpVar::new_witness(cs.clone(), || Ok(Fq::from(7u64))).unwrap();
+
+        let tau_minus_z_bits: Vec<Boolean<Fq>> = tau_minus_z
+            .into_bigint()
+            .to_bits_le()
+            .iter()
+            .map(|b| Boolean::new_witness(cs.clone(), || Ok(*b)).unwrap())
+            .collect();
+
+        KzgVerifierGadget::verify::<EdwardsProjective, EdwardsVar, LinearCombinationCheck>(
+            &commitment_var,
+            &y_var,
+            &g_var,
+            &w_var,
+            &h_var,
+            &tau_h_minus_zh_var,
+            &tau_minus_z_bits,
+        )
+        .unwrap();
+
+        assert!(cs.is_satisfied().unwrap());
+    }
+
+    #[test]
+    fn test_hyperkzg_fold_commitments_rejects_empty_input() {
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let config = test_config::<Fq>();
+
+        let result = HyperKzgVerifierGadget::fold_commitments::<EdwardsProjective, EdwardsVar>(
+            cs, &config, &[],
+        );
+
+        assert!(result.is_err());
+    }
+}
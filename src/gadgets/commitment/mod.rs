@@ -0,0 +1,4 @@
+// commitment scheme gadget들을 모아두는 카테고리 모듈
+// public_encryptions가 scheme별(예: elgamal) 디렉토리를 두는 것과 동일한 구조를 따른다
+pub mod kzg;
+pub mod pedersen;
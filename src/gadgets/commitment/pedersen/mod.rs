@@ -0,0 +1,116 @@
+use ark_ec::CurveGroup;
+use ark_r1cs_std::{
+    eq::EqGadget,
+    groups::CurveVar,
+    prelude::{Boolean, ToBitsGadget},
+    uint8::UInt8,
+};
+use ark_relations::r1cs::SynthesisError;
+
+// Pedersen commitment의 opening을 in-circuit에서 검증하는 gadget
+// ElGamalEncGadget(gadgets/public_encryptions/elgamal)이 CurveVar<C, C::BaseField> 위에서
+// 동작하는 것과 동일한 방식으로, generator 벡터와 message bit들의 내적에 blinding을 더해
+// commitment를 재구성한 뒤 입력으로 주어진 commitment var와 비교한다
+
+// Pedersen의 public parameter: message 각 bit에 대응되는 generator들과, blinding에 사용할 h
+#[derive(Clone)]
+pub struct ParametersVar<C: CurveGroup, GG: CurveVar<C, C::BaseField>> {
+    pub generators: Vec<GG>,
+    pub h: GG,
+    _curve: std::marker::PhantomData<C>,
+}
+
+impl<C: CurveGroup, GG: CurveVar<C, C::BaseField>> ParametersVar<C, GG> {
+    pub fn new(generators: Vec<GG>, h: GG) -> Self {
+        Self {
+            generators,
+            h,
+            _curve: std::marker::PhantomData,
+        }
+    }
+}
+
+pub struct PedersenGadget;
+
+impl PedersenGadget {
+    // message를 UInt8 바이트들로 받아 little-endian bit로 펼친 뒤,
+    // cm = <generators, msg_bits> + r·h 를 계산하여 입력 commitment var와 일치하는지 강제한다
+    pub fn check_opening<C, GG>(
+        parameters: &ParametersVar<C, GG>,
+        message: &[UInt8<C::BaseField>],
+        randomness_bits: &[Boolean<C::BaseField>],
+        commitment: &GG,
+    ) -> Result<(), SynthesisError>
+    where
+        C: CurveGroup,
+        GG: CurveVar<C, C::BaseField>,
+    {
+        // message를 가장 작은 단위인 bit로 펼침 (ElGamalEncGadget::encrypt의 randomness 처리와 동일한 방식)
+        let msg_bits = message
+            .iter()
+            .flat_map(|b| b.to_bits_le().unwrap())
+            .collect::<Vec<_>>();
+
+        // bit 수와 generator 수가 어긋나면 zip이 짧은 쪽으로 조용히 잘려서 남는 message bit가
+        // commitment에 전혀 반영되지 않으므로(prover가 자유롭게 바꿀 수 있게 됨), 먼저 길이를 맞춘다
+        if msg_bits.len() != parameters.generators.len() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        // <generators, msg_bits> = sum_i msg_bits[i] * generators[i]
+        // 각 bit는 0/1이므로 scalar_mul_le에 bit 하나씩 넣어 conditional select하는 것과 동치
+        let mut acc = GG::zero();
+        for (bit, gen) in msg_bits.iter().zip(parameters.generators.iter()) {
+            acc += gen.scalar_mul_le(std::iter::once(bit))?;
+        }
+
+        // blinding term r·h
+        let blinding = parameters.h.scalar_mul_le(randomness_bits.iter())?;
+
+        let computed = acc + blinding;
+        computed.enforce_equal(commitment)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_ed_on_bn254::{Fq, constraints::EdwardsVar, EdwardsProjective};
+    use ark_r1cs_std::{alloc::AllocVar, groups::CurveVar as _, R1CSVar};
+    use ark_relations::r1cs::ConstraintSystem;
+    use ark_std::{test_rng, UniformRand};
+
+    #[test]
+    fn test_pedersen_opening() {
+        let rng = &mut test_rng();
+        let cs = ConstraintSystem::<Fq>::new_ref();
+
+        let generators = (0..8)
+            .map(|_| EdwardsVar::new_constant(cs.clone(), EdwardsProjective::rand(rng)).unwrap())
+            .collect::<Vec<_>>();
+        let h = EdwardsVar::new_constant(cs.clone(), EdwardsProjective::rand(rng)).unwrap();
+        let parameters = ParametersVar::<EdwardsProjective, EdwardsVar>::new(generators.clone(), h.clone());
+
+        let message = UInt8::new_witness_vec(cs.clone(), &[0b0000_0101u8]).unwrap();
+        let r_bits = vec![Boolean::new_witness(cs.clone(), || Ok(true)).unwrap()];
+
+        // circuit과 동일한 방식으로 기대값을 직접 계산
+        let msg_bits = message[0].to_bits_le().unwrap();
+        let mut expected = EdwardsVar::zero();
+        for (bit, gen) in msg_bits.iter().zip(generators.iter()) {
+            expected += gen.scalar_mul_le(std::iter::once(bit)).unwrap();
+        }
+        expected += h.scalar_mul_le(r_bits.iter()).unwrap();
+
+        PedersenGadget::check_opening::<EdwardsProjective, EdwardsVar>(
+            &parameters,
+            &message,
+            &r_bits,
+            &expected,
+        )
+        .unwrap();
+
+        assert!(cs.is_satisfied().unwrap());
+        let _ = expected.value();
+    }
+}
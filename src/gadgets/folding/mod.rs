@@ -0,0 +1,312 @@
+use crate::gadgets::poseidon_transcript::PoseidonTranscriptVar;
+use ark_crypto_primitives::sponge::poseidon::PoseidonConfig;
+use ark_ec::CurveGroup;
+use ark_ff::{BigInteger, PrimeField};
+use ark_r1cs_std::{
+    R1CSVar,
+    alloc::AllocVar,
+    boolean::Boolean,
+    eq::EqGadget,
+    fields::{FieldVar, ToConstraintFieldGadget, nonnative::NonNativeFieldVar},
+    groups::CurveVar,
+    select::CondSelectGadget,
+};
+use ark_relations::r1cs::{ConstraintSynthesizer, ConstraintSystemRef, SynthesisError};
+
+// Nova의 non-interactive folding scheme (NIFS.Verify)를 in-circuit으로 구현한 folding 모듈
+// PoseidonTranscriptVar에서 squeeze한 challenge를 사용해
+// 두 relaxed R1CS instance를 하나의 instance로 접는(fold) 과정을 검증한다
+//
+// folding 대상 값 중 u, x는 main curve C의 scalar field 원소이므로, base field C::BaseField 위의
+// 회로 안에서는 native하게 표현할 수 없어 NonNativeFieldVar로 표현한다 (nonnative field arithmetic)
+// 반면 commitment cmE, cmW는 curve C의 point이므로 main curve 위에서 native GG로 표현 가능하다
+//
+// 단, r·(curve point) 꼴의 scalar multiplication과 point 덧셈은 NIFSVerifierGadget에서 수행하지 않고
+// CycleFold 기법에 따라 별도 회로로 분리한다: C와 스칼라/베이스 필드가 뒤바뀐
+// 보조 곡선(auxiliary curve) 위에서는 C의 point가 native field 원소로 표현되므로, 그 point들의
+// scalar multiplication과 덧셈을 auxiliary curve 회로(CycleFoldCircuit)에서 native하게 검증한다
+
+// 하나의 relaxed R1CS instance (commitment 부분만) 를 나타내는 var
+// cmE, cmW: main curve 위의 commitment (native)
+// u, x: main curve의 scalar field 원소지만, 이 회로는 base field 위에서 동작하므로 nonnative로 표현
+#[derive(Clone)]
+pub struct PhiVar<C: CurveGroup, GG: CurveVar<C, C::BaseField>> {
+    pub cm_e: GG,
+    pub u: NonNativeFieldVar<C::ScalarField, C::BaseField>,
+    pub cm_w: GG,
+    pub x: Vec<NonNativeFieldVar<C::ScalarField, C::BaseField>>,
+}
+
+// folding 후 nonnative scalar part (u'', x'')만 담은 결과. cmE''/cmW''는 CycleFoldCircuit이 계산한다
+pub struct FoldedScalars<C: CurveGroup> {
+    pub u: NonNativeFieldVar<C::ScalarField, C::BaseField>,
+    pub x: Vec<NonNativeFieldVar<C::ScalarField, C::BaseField>>,
+    // companion curve에서 cmE'', cmW''를 계산할 때 사용할 challenge r의 bit 표현
+    pub r_bits: Vec<Boolean<C::BaseField>>,
+}
+
+pub struct NIFSVerifierGadget;
+
+impl NIFSVerifierGadget {
+    // NIFS.Verify: phi1, phi2와 cross-term commitment cmT를 transcript에 absorb하여 challenge r을
+    // 뽑고, u'' = u1 + r·u2, x'' = x1 + r·x2 를 nonnative field 연산으로 강제한다.
+    // cmE'' = cmE1 + r·cmT + r^2·cmE2, cmW'' = cmW1 + r·cmW2 는 CycleFoldCircuit에 위임한다
+    pub fn verify<C, GG>(
+        cs: ConstraintSystemRef<C::BaseField>,
+        config: &PoseidonConfig<C::BaseField>,
+        phi1: &PhiVar<C, GG>,
+        phi2: &PhiVar<C, GG>,
+        cm_t: &GG,
+    ) -> Result<FoldedScalars<C>, SynthesisError>
+    where
+        C: CurveGroup,
+        C::BaseField: PrimeField,
+        GG: CurveVar<C, C::BaseField> + ToConstraintFieldGadget<C::BaseField>,
+    {
+        // 1. 두 instance와 cross-term commitment를 transcript에 absorb
+        let zero = ark_r1cs_std::fields::fp::FpVar::new_constant(cs.clone(), C::BaseField::from(0u64))?;
+        let mut transcript = PoseidonTranscriptVar::new(cs.clone(), config, zero)?;
+
+        transcript.append_vector(&phi1.cm_e.to_constraint_field()?)?;
+        transcript.append_vector(&phi1.cm_w.to_constraint_field()?)?;
+        transcript.append_vector(&phi2.cm_e.to_constraint_field()?)?;
+        transcript.append_vector(&phi2.cm_w.to_constraint_field()?)?;
+        transcript.append_vector(&cm_t.to_constraint_field()?)?;
+
+        // 2. challenge r을 squeeze (추후 r·point 연산에 쓰일 bit 표현도 함께 뽑음)
+        let r = transcript.challenge()?;
+        let r_bits = r.to_bits_le()?;
+
+        // 3. nonnative scalar part 접기: u'' = u1 + r·u2
+        //
+        // r_nonnative는 산술 효율을 위해 r.value()로부터 미리 witness되지만, 그 값이 실제로
+        // transcript가 squeeze한 r과 같은 challenge인지는 이 witness 자체로는 전혀 강제되지
+        // 않는다. r_bits(이미 r로부터 to_bits_le로 비트 분해가 강제된 값)로부터 bit-by-bit로
+        // nonnative 값을 직접 재구성한 뒤 r_nonnative와 enforce_equal 해서, 이 folding에 쓰이는
+        // challenge가 Fiat-Shamir transcript에서 나온 것과 동일함을 회로 안에서 강제한다
+        let r_nonnative = NonNativeFieldVar::<C::ScalarField, C::BaseField>::new_witness(
+            cs.clone(),
+            || r.value().map(|v| {
+                // base field 원소 r의 bit-width가 scalar field의 modulus보다 클 수 있으므로,
+                // bit를 scalar field의 BigInt로 그대로 재해석(from_bigint)하면 modulus를 넘는
+                // 값에서 None이 되어 조용히 0으로 깨진다. byte 표현을 실제로 modulus로 환원하는
+                // from_le_bytes_mod_order를 써야 in-circuit double-and-add 결과와 일치한다
+                let bytes = v.into_bigint().to_bytes_le();
+                C::ScalarField::from_le_bytes_mod_order(&bytes)
+            }),
+        )?;
+        let r_nonnative_from_bits = recompose_nonnative_from_bits::<C>(&r_bits)?;
+        r_nonnative.enforce_equal(&r_nonnative_from_bits)?;
+
+        let u_folded = &phi1.u + &r_nonnative * &phi2.u;
+
+        // x'' = x1 + r·x2 (원소별)
+        let x_folded: Vec<_> = phi1
+            .x
+            .iter()
+            .zip(phi2.x.iter())
+            .map(|(x1, x2)| x1 + &r_nonnative * x2)
+            .collect();
+
+        Ok(FoldedScalars {
+            u: u_folded,
+            x: x_folded,
+            r_bits,
+        })
+    }
+}
+
+// r_bits(base field 위의 native bit들)로부터 scalar field 위의 NonNativeFieldVar를 double-and-add로
+// 직접 구성한다. r_bits가 이미 transcript의 challenge r로부터 to_bits_le로 비트 분해된 값이므로,
+// 이 결과와 별도로 witness된 r_nonnative를 enforce_equal 하면 두 값이 같은 challenge에서
+// 나왔음이 회로 안에서 강제된다 (bit 자체를 독립적으로 witness하지 않는다)
+fn recompose_nonnative_from_bits<C>(
+    bits: &[Boolean<C::BaseField>],
+) -> Result<NonNativeFieldVar<C::ScalarField, C::BaseField>, SynthesisError>
+where
+    C: CurveGroup,
+    C::BaseField: PrimeField,
+{
+    let mut acc = NonNativeFieldVar::<C::ScalarField, C::BaseField>::zero();
+    let mut coeff = NonNativeFieldVar::<C::ScalarField, C::BaseField>::one();
+    for bit in bits.iter() {
+        let term = NonNativeFieldVar::conditionally_select(
+            bit,
+            &coeff,
+            &NonNativeFieldVar::zero(),
+        )?;
+        acc += term;
+        coeff = &coeff + &coeff;
+    }
+    Ok(acc)
+}
+
+// CycleFold 보조 곡선 회로: auxiliary curve 위에서는 main curve C의 point들이 native field
+// 원소로 표현되므로, cmE'' = cmE1 + r·cmT + r^2·cmE2, cmW'' = cmW1 + r·cmW2 를 native
+// scalar_mul_le / 덧셈으로 직접 검증할 수 있다. r은 main 회로가 squeeze한 challenge의 bit를 그대로 공개 입력으로 받는다
+pub struct CycleFoldCircuit<C, GG>
+where
+    C: CurveGroup,
+    GG: CurveVar<C, C::BaseField>,
+{
+    // witness: 접히기 전 commitment들과 cross-term commitment
+    pub cm_e1: Option<C>,
+    pub cm_w1: Option<C>,
+    pub cm_e2: Option<C>,
+    pub cm_w2: Option<C>,
+    pub cm_t: Option<C>,
+    // public input: main 회로에서 squeeze된 challenge r의 bit 표현
+    pub r_bits: Option<Vec<bool>>,
+    // public input: 검증하고자 하는 folded commitment
+    pub cm_e_folded: Option<C>,
+    pub cm_w_folded: Option<C>,
+    _group_var: std::marker::PhantomData<GG>,
+}
+
+impl<C, GG> ConstraintSynthesizer<C::BaseField> for CycleFoldCircuit<C, GG>
+where
+    C: CurveGroup,
+    C::BaseField: PrimeField,
+    GG: CurveVar<C, C::BaseField>,
+{
+    fn generate_constraints(
+        self,
+        cs: ConstraintSystemRef<C::BaseField>,
+    ) -> ark_relations::r1cs::Result<()> {
+        use ark_r1cs_std::eq::EqGadget;
+
+        let cm_e1 = GG::new_witness(cs.clone(), || {
+            self.cm_e1.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let cm_w1 = GG::new_witness(cs.clone(), || {
+            self.cm_w1.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let cm_e2 = GG::new_witness(cs.clone(), || {
+            self.cm_e2.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let cm_w2 = GG::new_witness(cs.clone(), || {
+            self.cm_w2.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let cm_t = GG::new_witness(cs.clone(), || {
+            self.cm_t.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+
+        let r_bits = self
+            .r_bits
+            .ok_or(SynthesisError::AssignmentMissing)?
+            .iter()
+            .map(|b| Boolean::new_input(cs.clone(), || Ok(*b)))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // r^2·cmE2는 cmE2를 r배 한 뒤 다시 r배 하는 것으로 계산 (bit별 r^2를 따로 구할 필요 없음)
+        let r_ct = cm_t.scalar_mul_le(r_bits.iter())?;
+        let r_e2 = cm_e2.scalar_mul_le(r_bits.iter())?;
+        let r2_e2 = r_e2.scalar_mul_le(r_bits.iter())?;
+        // cmE'' = cmE1 + r·cmT + r^2·cmE2
+        let cm_e_folded = cm_e1 + r_ct + r2_e2;
+        // cmW'' = cmW1 + r·cmW2
+        let cm_w_folded = cm_w1 + cm_w2.scalar_mul_le(r_bits.iter())?;
+
+        let expected_e = GG::new_input(cs.clone(), || {
+            self.cm_e_folded.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+        let expected_w = GG::new_input(cs.clone(), || {
+            self.cm_w_folded.ok_or(SynthesisError::AssignmentMissing)
+        })?;
+
+        cm_e_folded.enforce_equal(&expected_e)?;
+        cm_w_folded.enforce_equal(&expected_w)?;
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gadgets::poseidon_transcript::test_config;
+    use ark_ed_on_bn254::{Fq, Fr, EdwardsProjective, constraints::EdwardsVar};
+    use ark_relations::r1cs::ConstraintSystem;
+    use ark_std::{UniformRand, test_rng};
+
+    #[test]
+    fn test_nifs_verify_folds_scalars_and_satisfies_constraints() {
+        let rng = &mut test_rng();
+        let cs = ConstraintSystem::<Fq>::new_ref();
+        let config = test_config::<Fq>();
+
+        let phi1 = PhiVar::<EdwardsProjective, EdwardsVar> {
+            cm_e: EdwardsVar::new_witness(cs.clone(), || Ok(EdwardsProjective::rand(rng))).unwrap(),
+            u: NonNativeFieldVar::new_witness(cs.clone(), || Ok(Fr::from(3u64))).unwrap(),
+            cm_w: EdwardsVar::new_witness(cs.clone(), || Ok(EdwardsProjective::rand(rng))).unwrap(),
+            x: vec![NonNativeFieldVar::new_witness(cs.clone(), || Ok(Fr::from(5u64))).unwrap()],
+        };
+        let phi2 = PhiVar::<EdwardsProjective, EdwardsVar> {
+            cm_e: EdwardsVar::new_witness(cs.clone(), || Ok(EdwardsProjective::rand(rng))).unwrap(),
+            u: NonNativeFieldVar::new_witness(cs.clone(), || Ok(Fr::from(7u64))).unwrap(),
+            cm_w: EdwardsVar::new_witness(cs.clone(), || Ok(EdwardsProjective::rand(rng))).unwrap(),
+            x: vec![NonNativeFieldVar::new_witness(cs.clone(), || Ok(Fr::from(11u64))).unwrap()],
+        };
+        let cm_t = EdwardsVar::new_witness(cs.clone(), || Ok(EdwardsProjective::rand(rng))).unwrap();
+
+        let folded =
+            NIFSVerifierGadget::verify::<EdwardsProjective, EdwardsVar>(cs.clone(), &config, &phi1, &phi2, &cm_t)
+                .unwrap();
+
+        assert!(cs.is_satisfied().unwrap());
+
+        // squeeze된 r_bits로부터 native하게 r을 재구성해, folded 값이 실제로 u1 + r*u2 (원소별로는
+        // x1 + r*x2) 관계를 만족하는지 회로 밖에서 다시 확인한다
+        let r_bits_le: Vec<bool> = folded
+            .r_bits
+            .iter()
+            .map(|b| b.value().unwrap())
+            .collect();
+        // production 코드와 동일하게, base field bit 표현을 byte로 바꾼 뒤 scalar field로
+        // modulus 환원한다 (from_bigint 재해석은 scalar field modulus를 넘는 값에서 깨진다)
+        let r_bytes = <Fq as PrimeField>::BigInt::from_bits_le(&r_bits_le).to_bytes_le();
+        let r = Fr::from_le_bytes_mod_order(&r_bytes);
+
+        assert_eq!(
+            folded.u.value().unwrap(),
+            Fr::from(3u64) + r * Fr::from(7u64)
+        );
+        assert_eq!(
+            folded.x[0].value().unwrap(),
+            Fr::from(5u64) + r * Fr::from(11u64)
+        );
+    }
+
+    #[test]
+    fn test_cyclefold_circuit_satisfied() {
+        let rng = &mut test_rng();
+        let cs = ConstraintSystem::<Fq>::new_ref();
+
+        let cm_e1 = EdwardsProjective::rand(rng);
+        let cm_w1 = EdwardsProjective::rand(rng);
+        let cm_e2 = EdwardsProjective::rand(rng);
+        let cm_w2 = EdwardsProjective::rand(rng);
+        let cm_t = EdwardsProjective::rand(rng);
+        let r = Fr::from(13u64);
+        let r_bits = r.into_bigint().to_bits_le();
+
+        // cmE'' = cmE1 + r·cmT + r^2·cmE2, cmW'' = cmW1 + r·cmW2 를 circuit 밖에서 그대로 계산
+        let cm_e_folded = cm_e1 + cm_t * r + cm_e2 * (r * r);
+        let cm_w_folded = cm_w1 + cm_w2 * r;
+
+        let circuit = CycleFoldCircuit::<EdwardsProjective, EdwardsVar> {
+            cm_e1: Some(cm_e1),
+            cm_w1: Some(cm_w1),
+            cm_e2: Some(cm_e2),
+            cm_w2: Some(cm_w2),
+            cm_t: Some(cm_t),
+            r_bits: Some(r_bits),
+            cm_e_folded: Some(cm_e_folded),
+            cm_w_folded: Some(cm_w_folded),
+            _group_var: std::marker::PhantomData,
+        };
+
+        circuit.generate_constraints(cs.clone()).unwrap();
+        assert!(cs.is_satisfied().unwrap());
+    }
+}
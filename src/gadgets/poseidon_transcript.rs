@@ -0,0 +1,113 @@
+use ark_crypto_primitives::sponge::{
+    constraints::CryptographicSpongeVar,
+    poseidon::{PoseidonConfig, constraints::PoseidonSpongeVar},
+};
+use ark_ff::PrimeField;
+use ark_r1cs_std::{boolean::Boolean, fields::fp::FpVar, prelude::ToBitsGadget};
+use ark_relations::r1cs::{ConstraintSystemRef, SynthesisError};
+
+// Fiat-Shamir transform을 in-circuit에서 수행하기 위한 transcript(sponge) 래퍼
+// Poseidon sponge에 값을 absorb하고, 그로부터 verifier challenge를 squeeze하는 역할을 담당
+// AgeCircuit2, PolyCircuit, ElGamalEncGadget 등은 challenge를 직접 계산하지 않지만,
+// recursive/folding verifier (예: Nova NIFS.Verify)는 in-circuit challenge 생성이 필수적이므로
+// 모든 gadget이 공유할 수 있는 transcript를 이곳에 둔다
+pub struct PoseidonTranscriptVar<F: PrimeField> {
+    sponge: PoseidonSpongeVar<F>,
+}
+
+impl<F: PrimeField> PoseidonTranscriptVar<F> {
+    // init 값(예: 도메인 separator)을 첫 원소로 absorb한 뒤 transcript를 생성
+    pub fn new(
+        cs: ConstraintSystemRef<F>,
+        config: &PoseidonConfig<F>,
+        init: FpVar<F>,
+    ) -> Result<Self, SynthesisError> {
+        let mut sponge = PoseidonSpongeVar::new(cs, config);
+        sponge.absorb(&init)?;
+        Ok(Self { sponge })
+    }
+
+    // 원소 하나를 sponge에 absorb
+    pub fn append(&mut self, elem: &FpVar<F>) -> Result<(), SynthesisError> {
+        self.sponge.absorb(elem)
+    }
+
+    // 여러 원소를 한 번에 absorb
+    pub fn append_vector(&mut self, elems: &[FpVar<F>]) -> Result<(), SynthesisError> {
+        self.sponge.absorb(&elems.to_vec())
+    }
+
+    // sponge에서 field element 하나를 squeeze하여 challenge로 사용
+    pub fn challenge(&mut self) -> Result<FpVar<F>, SynthesisError> {
+        let squeezed = self.sponge.squeeze_field_elements(1)?;
+        Ok(squeezed[0].clone())
+    }
+
+    // challenge를 squeeze한 뒤 하위 n bit(little-endian)만 잘라서 반환
+    // (bit 길이가 고정된 challenge가 필요한 scalar_mul_le 등에 사용)
+    pub fn challenge_nbits(&mut self, n: usize) -> Result<Vec<Boolean<F>>, SynthesisError> {
+        let c = self.challenge()?;
+        let bits = c.to_bits_le()?;
+        Ok(bits[..n].to_vec())
+    }
+}
+
+// 테스트용 Poseidon config를 만드는 헬퍼. folding/kzg 모듈의 테스트도 같은 설정이 필요하므로
+// 여기 한 곳에만 두고 crate 내부에 공유한다 (파일마다 복붙하지 않는다)
+#[cfg(test)]
+pub(crate) fn test_config<F: PrimeField>() -> PoseidonConfig<F> {
+    use ark_crypto_primitives::sponge::poseidon::find_poseidon_ark_and_mds;
+
+    let full_rounds = 8;
+    let partial_rounds = 31;
+    let alpha = 5;
+    let rate = 2;
+    let capacity = 1;
+    let (ark, mds) = find_poseidon_ark_and_mds::<F>(
+        F::MODULUS_BIT_SIZE as u64,
+        rate,
+        full_rounds,
+        partial_rounds,
+        0,
+    );
+    PoseidonConfig::new(
+        full_rounds as usize,
+        partial_rounds as usize,
+        alpha,
+        mds,
+        ark,
+        rate,
+        capacity,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+    use ark_r1cs_std::{alloc::AllocVar, eq::EqGadget};
+    use ark_relations::r1cs::ConstraintSystem;
+
+    #[test]
+    fn test_transcript_is_deterministic() {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+        let config = test_config::<Fr>();
+
+        let zero = FpVar::new_constant(cs.clone(), Fr::from(0u64)).unwrap();
+        let a = FpVar::new_witness(cs.clone(), || Ok(Fr::from(7u64))).unwrap();
+        let b = FpVar::new_witness(cs.clone(), || Ok(Fr::from(11u64))).unwrap();
+
+        let mut t1 = PoseidonTranscriptVar::new(cs.clone(), &config, zero.clone()).unwrap();
+        t1.append(&a).unwrap();
+        t1.append_vector(&[b.clone()]).unwrap();
+        let c1 = t1.challenge().unwrap();
+
+        let mut t2 = PoseidonTranscriptVar::new(cs.clone(), &config, zero).unwrap();
+        t2.append(&a).unwrap();
+        t2.append_vector(&[b]).unwrap();
+        let c2 = t2.challenge().unwrap();
+
+        c1.enforce_equal(&c2).unwrap();
+        assert!(cs.is_satisfied().unwrap());
+    }
+}
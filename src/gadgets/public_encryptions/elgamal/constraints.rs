@@ -211,6 +211,38 @@ where
     }
 }
 
+impl<C, GG> ElGamalEncGadget<C, GG>
+where
+    C: CurveGroup,
+    GG: CurveVar<C, C::BaseField>,
+    C::BaseField: PrimeField,
+{
+    // ElGamal 복호화: m = c2 - sk·c1
+    // secret key bit는 little-endian으로 주어지며, scalar_mul_le로 sk·c1을 계산한 뒤 c2에서 뺀다
+    pub fn decrypt(
+        ciphertext: &OutputVar<C, GG>,
+        secret_key_bits: &[Boolean<C::BaseField>],
+        plaintext: &PlaintextVar<C, GG>,
+    ) -> Result<(), SynthesisError> {
+        let sk_c1 = ciphertext.c1.scalar_mul_le(secret_key_bits.iter())?;
+        let m = ciphertext.c2.clone() - sk_c1;
+        m.enforce_equal(&plaintext.plaintext)
+    }
+
+    // ElGamal의 additive homomorphism을 이용한 ciphertext 덧셈: (c1+c1', c2+c2')
+    // 투표 집계와 같이 개별 ciphertext의 합이 합계의 ciphertext와 같음을 증명할 때 사용
+    pub fn add_ciphertexts(
+        lhs: &OutputVar<C, GG>,
+        rhs: &OutputVar<C, GG>,
+    ) -> Result<OutputVar<C, GG>, SynthesisError> {
+        Ok(OutputVar {
+            c1: lhs.c1.clone() + rhs.c1.clone(),
+            c2: lhs.c2.clone() + rhs.c2.clone(),
+            _curve: PhantomData,
+        })
+    }
+}
+
 #[cfg(test)]
 mod test {
     use ark_std::{UniformRand, test_rng};
@@ -285,4 +317,64 @@ mod test {
         assert_eq!(primitive_result.1, result_var.c2.value().unwrap());
         assert!(cs.is_satisfied().unwrap());
     }
+
+    #[test]
+    fn test_elgamal_decrypt_and_tally_gadget() {
+        let rng = &mut test_rng();
+
+        type MyEnc = ElGamal<EdwardsProjective>;
+        type MyGadget = ElGamalEncGadget<EdwardsProjective, EdwardsVar>;
+
+        // 두 명의 유권자가 각자의 표를 암호화하고, 집계(tally)가 개별 ciphertext의 합과
+        // 동일한 평문으로 복호화되는지를 검증하는 시나리오 (개별 표는 공개되지 않음)
+        let parameters = MyEnc::setup(rng).unwrap();
+        let (pk, sk) = MyEnc::keygen(&parameters, rng).unwrap();
+
+        let vote1 = EdwardsProjective::rand(rng);
+        let vote2 = EdwardsProjective::rand(rng);
+        let r1 = Randomness::rand(rng);
+        let r2 = Randomness::rand(rng);
+        let ct1 = MyEnc::encrypt(&parameters, &pk, &vote1.into(), &r1).unwrap();
+        let ct2 = MyEnc::encrypt(&parameters, &pk, &vote2.into(), &r2).unwrap();
+
+        let tally = vote1 + vote2;
+
+        let cs = ConstraintSystem::<Fq>::new_ref();
+
+        let ct1_var =
+            <MyGadget as AsymmetricEncryptionGadget<MyEnc, Fq>>::OutputVar::new_witness(
+                ark_relations::ns!(cs, "ct1"),
+                || Ok(&ct1),
+            )
+            .unwrap();
+        let ct2_var =
+            <MyGadget as AsymmetricEncryptionGadget<MyEnc, Fq>>::OutputVar::new_witness(
+                ark_relations::ns!(cs, "ct2"),
+                || Ok(&ct2),
+            )
+            .unwrap();
+
+        // 개별 표를 공개하지 않고 ciphertext만 더해 집계
+        let summed_var = MyGadget::add_ciphertexts(&ct1_var, &ct2_var).unwrap();
+
+        // secret key를 little-endian bit로 풀어 scalar_mul_le에 사용
+        let sk_bits: Vec<ark_r1cs_std::boolean::Boolean<Fq>> = ark_ff::BigInteger::to_bits_le(
+            &ark_ff::PrimeField::into_bigint(sk.0),
+        )
+        .iter()
+        .map(|b| ark_r1cs_std::boolean::Boolean::new_witness(cs.clone(), || Ok(*b)).unwrap())
+        .collect();
+
+        let tally_plain = tally.into();
+        let tally_var =
+            <MyGadget as AsymmetricEncryptionGadget<MyEnc, Fq>>::PlaintextVar::new_input(
+                ark_relations::ns!(cs, "tally"),
+                || Ok(&tally_plain),
+            )
+            .unwrap();
+
+        MyGadget::decrypt(&summed_var, &sk_bits, &tally_var).unwrap();
+
+        assert!(cs.is_satisfied().unwrap());
+    }
 }
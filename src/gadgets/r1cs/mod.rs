@@ -0,0 +1,144 @@
+use ark_ff::PrimeField;
+use ark_r1cs_std::{eq::EqGadget, fields::FieldVar};
+use ark_relations::r1cs::SynthesisError;
+
+// Nova 스타일 folding verifier를 호스팅하기 위한 relaxed R1CS in-circuit 검증 gadget
+// relaxed R1CS: Az∘Bz = u·Cz + E  (u=1, E=0이면 일반 R1CS와 동일)
+// 이 gadget은 folded instance/witness가 실제로 이 관계를 만족하는지 증명하는데 사용됨
+
+// R1CS 행렬의 한 row를 (계수, column index) 쌍의 목록으로 표현하는 sparse matrix
+// F: 행렬 원소가 나타내는 값의 field, CF: 이 gadget이 실제로 동작하는 constraint system의 field
+// (folding 모듈처럼 FV가 nonnative field var인 경우 F != CF가 되므로 둘을 분리해서 받는다)
+#[derive(Clone)]
+pub struct SparseMatrixVar<F: PrimeField, CF: PrimeField, FV: FieldVar<F, CF>> {
+    pub n_rows: usize,
+    pub n_cols: usize,
+    // row별 (nonzero 계수, column index) 목록
+    pub rows: Vec<Vec<(FV, usize)>>,
+    _field: std::marker::PhantomData<(F, CF)>,
+}
+
+impl<F: PrimeField, CF: PrimeField, FV: FieldVar<F, CF>> SparseMatrixVar<F, CF, FV> {
+    pub fn new(n_rows: usize, n_cols: usize, rows: Vec<Vec<(FV, usize)>>) -> Self {
+        Self {
+            n_rows,
+            n_cols,
+            rows,
+            _field: std::marker::PhantomData,
+        }
+    }
+}
+
+// relaxed R1CS instance + witness를 하나로 모은 var
+// (A, B, C는 public parameter이므로 matrix 자체는 witness가 아니라 circuit에 고정된 값으로 취급)
+#[derive(Clone)]
+pub struct RelaxedR1CSVar<F: PrimeField, CF: PrimeField, FV: FieldVar<F, CF>> {
+    pub a: SparseMatrixVar<F, CF, FV>,
+    pub b: SparseMatrixVar<F, CF, FV>,
+    pub c: SparseMatrixVar<F, CF, FV>,
+    // slack vector E와 scalar u: folding 과정에서 누적되는 relaxation term
+    pub e: Vec<FV>,
+    pub u: FV,
+}
+
+// sparse matrix-vector 곱: res[row] += value * z[col]
+pub fn mat_vec_mul_sparse<F: PrimeField, CF: PrimeField, FV: FieldVar<F, CF>>(
+    m: &SparseMatrixVar<F, CF, FV>,
+    z: &[FV],
+) -> Result<Vec<FV>, SynthesisError> {
+    let mut res = vec![FV::zero(); m.n_rows];
+    for (row_idx, row) in m.rows.iter().enumerate() {
+        for (value, col) in row.iter() {
+            // FieldVar는 generic bound 상 `Self op &Self`/`Self op Self`만 보장하고
+            // `&Self op &Self`는 보장하지 않으므로, 좌변을 clone해서 넘긴다
+            res[row_idx] += value.clone() * &z[*col];
+        }
+    }
+    Ok(res)
+}
+
+// 두 벡터의 원소별 곱 (Hadamard product)
+pub fn hadamard<F: PrimeField, CF: PrimeField, FV: FieldVar<F, CF>>(
+    a: &[FV],
+    b: &[FV],
+) -> Result<Vec<FV>, SynthesisError> {
+    Ok(a.iter().zip(b.iter()).map(|(x, y)| x.clone() * y).collect())
+}
+
+// 두 벡터의 원소별 합
+pub fn vec_add<F: PrimeField, CF: PrimeField, FV: FieldVar<F, CF>>(
+    a: &[FV],
+    b: &[FV],
+) -> Result<Vec<FV>, SynthesisError> {
+    Ok(a.iter().zip(b.iter()).map(|(x, y)| x.clone() + y).collect())
+}
+
+// 벡터의 scalar 배
+pub fn vec_scalar_mul<F: PrimeField, CF: PrimeField, FV: FieldVar<F, CF>>(
+    v: &[FV],
+    s: &FV,
+) -> Result<Vec<FV>, SynthesisError> {
+    Ok(v.iter().map(|x| x.clone() * s).collect())
+}
+
+pub struct RelaxedR1CSGadget;
+
+impl RelaxedR1CSGadget {
+    // z = (witness || public input || 1)에 대해 relaxed R1CS 관계 Az∘Bz = u·Cz + E 를 강제
+    pub fn check<F: PrimeField, CF: PrimeField, FV: FieldVar<F, CF>>(
+        rel_r1cs: &RelaxedR1CSVar<F, CF, FV>,
+        z: &[FV],
+    ) -> Result<(), SynthesisError> {
+        let az = mat_vec_mul_sparse(&rel_r1cs.a, z)?;
+        let bz = mat_vec_mul_sparse(&rel_r1cs.b, z)?;
+        let cz = mat_vec_mul_sparse(&rel_r1cs.c, z)?;
+
+        // A/B/C의 row 수와 slack vector E의 길이가 어긋나면 아래 zip이 짧은 쪽으로 조용히
+        // 잘려서 뒤쪽 row들의 관계가 전혀 강제되지 않으므로, 여기서 먼저 형태를 맞춰본다
+        if az.len() != bz.len() || bz.len() != cz.len() || cz.len() != rel_r1cs.e.len() {
+            return Err(SynthesisError::Unsatisfiable);
+        }
+
+        let u_cz = vec_scalar_mul(&cz, &rel_r1cs.u)?;
+        let u_cz_e = vec_add(&u_cz, &rel_r1cs.e)?;
+        let az_bz = hadamard(&az, &bz)?;
+
+        for (lhs, rhs) in az_bz.iter().zip(u_cz_e.iter()) {
+            lhs.enforce_equal(rhs)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use ark_bn254::Fr;
+    use ark_r1cs_std::{alloc::AllocVar, fields::fp::FpVar};
+    use ark_relations::r1cs::ConstraintSystem;
+
+    // 1x2 identity-like relaxed R1CS: z = (x, 1), A = B = C = [[1,0]]
+    // Az∘Bz = x^2, u·Cz + E = u·x + E 가 되도록 u, E를 선택해 만족시키는 간단한 예시
+    #[test]
+    fn test_relaxed_r1cs_satisfied() {
+        let cs = ConstraintSystem::<Fr>::new_ref();
+
+        let x = FpVar::new_witness(cs.clone(), || Ok(Fr::from(3u64))).unwrap();
+        let one = FpVar::new_constant(cs.clone(), Fr::from(1u64)).unwrap();
+        let z = vec![x.clone(), one];
+
+        let row = vec![(FpVar::new_constant(cs.clone(), Fr::from(1u64)).unwrap(), 0usize)];
+        let a = SparseMatrixVar::new(1, 2, vec![row.clone()]);
+        let b = SparseMatrixVar::new(1, 2, vec![row.clone()]);
+        let c = SparseMatrixVar::new(1, 2, vec![row]);
+
+        // Az∘Bz = x*x = 9, u·Cz + E = u·x + E 를 만족하도록 u=3, E=0 선택
+        let u = FpVar::new_witness(cs.clone(), || Ok(Fr::from(3u64))).unwrap();
+        let e = vec![FpVar::new_constant(cs.clone(), Fr::from(0u64)).unwrap()];
+
+        let rel_r1cs = RelaxedR1CSVar { a, b, c, e, u };
+
+        RelaxedR1CSGadget::check(&rel_r1cs, &z).unwrap();
+        assert!(cs.is_satisfied().unwrap());
+    }
+}